@@ -20,7 +20,7 @@
 //!
 //!     // Parse to specific unit
 //!     let kb: f64 = bytefmt::parse_to(input, bytefmt::Unit::KB).unwrap();
-//!     assert_eq!(kb, 1_230 as f64);
+//!     assert_eq!(kb, 1_230_f64);
 //!
 //!     // Format to specific unit
 //!     let kb_str = bytefmt::format_to(bytes, bytefmt::Unit::KB);
@@ -28,24 +28,22 @@
 //! }
 //! ```
 ////////////////////////////////////////////////////////////////////////////////
-extern crate regex;
-
-use regex::Regex;
-
 pub const B: u64 = 1;
 pub const KB: u64 = 1_000;
 pub const MB: u64 = 1_000_000;
 pub const GB: u64 = 1_000_000_000;
 pub const TB: u64 = 1_000_000_000_000;
 pub const PB: u64 = 1_000_000_000_000_000;
+pub const EB: u64 = 1_000_000_000_000_000_000;
 
 pub const KIB: u64 = 1_024;
 pub const MIB: u64 = 1_048_576;
 pub const GIB: u64 = 1_073_741_824;
 pub const TIB: u64 = 1_099_511_627_776;
 pub const PIB: u64 = 1_125_899_906_842_624;
+pub const EIB: u64 = 1_152_921_504_606_846_976;
 
-#[derive(Debug,PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Unit {
     B,
     KB,
@@ -53,43 +51,93 @@ pub enum Unit {
     GB,
     TB,
     PB,
+    EB,
     KIB,
     MIB,
     GIB,
     TIB,
     PIB,
+    EIB,
+}
+
+/// Which ladder of units `format_with` should pick from: decimal (SI, base
+/// 1000) or binary (IEC, base 1024).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum System {
+    Decimal,
+    Binary,
+}
+
+const DECIMAL_TABLE: &[(u64, Unit)] = &[
+    (EB, Unit::EB),
+    (PB, Unit::PB),
+    (TB, Unit::TB),
+    (GB, Unit::GB),
+    (MB, Unit::MB),
+    (KB, Unit::KB),
+];
+
+const BINARY_TABLE: &[(u64, Unit)] = &[
+    (EIB, Unit::EIB),
+    (PIB, Unit::PIB),
+    (TIB, Unit::TIB),
+    (GIB, Unit::GIB),
+    (MIB, Unit::MIB),
+    (KIB, Unit::KIB),
+];
+
+/// Checks that `s` matches `\d+(\.\d+)?`: one or more digits, optionally
+/// followed by a dot and one or more digits. Rejects bare/leading/trailing
+/// dots (e.g. ".5", "5.") so the magnitude grammar matches the old regex.
+fn is_valid_magnitude(s: &str) -> bool {
+    let mut parts = s.splitn(2, '.');
+
+    let int_part = parts.next().unwrap_or("");
+    if int_part.is_empty() || !int_part.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+
+    match parts.next() {
+        Some(frac_part) => !frac_part.is_empty() && frac_part.bytes().all(|b| b.is_ascii_digit()),
+        None => true,
+    }
 }
 
 fn parse_size_unit<S: Into<String>>(s: S) -> Result<(f64, Unit), &'static str> {
     let str = s.into();
-    let re = Regex::new(r"^(?i)(\d+(\.\d+)?) *((k|m|g|t|p|ki|mi|gi|ti|pi)?b)?$").unwrap();
-    let captures = re.captures(&str);
-    
-    match captures {
-        Some(res) => {
-            let size = res[1].to_owned();
-            let unit: String = match res.get(3) {
-                Some(val) => val.as_str().to_owned().to_uppercase(),
-                None => "B".to_owned(),
-            };
-            
-            Ok((size.parse::<f64>().unwrap(), match &*unit {
-                "B" => Unit::B,
-                "KB" => Unit::KB,
-                "MB" => Unit::MB,
-                "GB" => Unit::GB,
-                "TB" => Unit::TB,
-                "PB" => Unit::PB,
-                "KIB" => Unit::KIB,
-                "MIB" => Unit::MIB,
-                "GIB" => Unit::GIB,
-                "TIB" => Unit::TIB,
-                "PIB" => Unit::PIB,
-                _ => Unit::B,
-            }))
-        }
-        None => Err("Parse Error. Invalid byte format."),
+
+    let digits = str
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(str.len());
+
+    let (magnitude, rest) = str.split_at(digits);
+
+    if !is_valid_magnitude(magnitude) {
+        return Err("Parse Error. Invalid byte format.");
     }
+
+    let size = magnitude
+        .parse::<f64>()
+        .map_err(|_| "Parse Error. Invalid byte format.")?;
+
+    let unit = match &*rest.trim_start().to_uppercase() {
+        "" | "B" => Unit::B,
+        "KB" => Unit::KB,
+        "MB" => Unit::MB,
+        "GB" => Unit::GB,
+        "TB" => Unit::TB,
+        "PB" => Unit::PB,
+        "EB" => Unit::EB,
+        "KIB" => Unit::KIB,
+        "MIB" => Unit::MIB,
+        "GIB" => Unit::GIB,
+        "TIB" => Unit::TIB,
+        "PIB" => Unit::PIB,
+        "EIB" => Unit::EIB,
+        _ => return Err("Parse Error. Invalid byte format."),
+    };
+
+    Ok((size, unit))
 }
 
 /// Parse given string to bytes count
@@ -125,11 +173,13 @@ pub fn parse<S: Into<String>>(str: S) -> Result<u64, &'static str> {
                 Unit::GB => value * GB as f64,
                 Unit::TB => value * TB as f64,
                 Unit::PB => value * PB as f64,
+                Unit::EB => value * EB as f64,
                 Unit::KIB => value * KIB as f64,
                 Unit::MIB => value * MIB as f64,
                 Unit::GIB => value * GIB as f64,
                 Unit::TIB => value * TIB as f64,
                 Unit::PIB => value * PIB as f64,
+                Unit::EIB => value * EIB as f64,
             };
 
             Ok(bytes as u64)
@@ -159,11 +209,13 @@ pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, &'sta
                 Unit::GB => bytes as f64 / GB as f64,
                 Unit::TB => bytes as f64 / TB as f64,
                 Unit::PB => bytes as f64 / PB as f64,
+                Unit::EB => bytes as f64 / EB as f64,
                 Unit::KIB => bytes as f64 / KIB as f64,
                 Unit::MIB => bytes as f64 / MIB as f64,
                 Unit::GIB => bytes as f64 / GIB as f64,
                 Unit::TIB => bytes as f64 / TIB as f64,
                 Unit::PIB => bytes as f64 / PIB as f64,
+                Unit::EIB => bytes as f64 / EIB as f64,
             };
 
             Ok(result)
@@ -185,27 +237,47 @@ pub fn parse_to<S: Into<String>>(str: S, result_unit: Unit) -> Result<f64, &'sta
 /// assert_eq!(bytefmt::format(1_230_000_000_000_000), "1.23 PB");
 /// ```
 pub fn format(bytes: u64) -> String {
-    if bytes < KB {
-        return format_to(bytes, Unit::B);
-    }
-
-    if bytes < MB {
-        return format_to(bytes, Unit::KB);
-    }
+    format_from_table(bytes, DECIMAL_TABLE)
+}
 
-    if bytes < GB {
-        return format_to(bytes, Unit::MB);
-    }
+/// Format bytes to byte string, auto-selecting a binary (1024-based) unit
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(bytefmt::format_binary(123), "123 B");
+/// assert_eq!(bytefmt::format_binary(1_259), "1.23 KiB");
+/// assert_eq!(bytefmt::format_binary(1_048_576), "1 MiB");
+/// ```
+pub fn format_binary(bytes: u64) -> String {
+    format_from_table(bytes, BINARY_TABLE)
+}
 
-    if bytes < TB {
-        return format_to(bytes, Unit::GB);
+/// Format bytes to byte string, choosing the unit ladder from `system`
+///
+/// # Examples
+///
+/// ```
+/// use bytefmt::System;
+///
+/// assert_eq!(bytefmt::format_with(1_230, System::Decimal), "1.23 KB");
+/// assert_eq!(bytefmt::format_with(1_259, System::Binary), "1.23 KiB");
+/// ```
+pub fn format_with(bytes: u64, system: System) -> String {
+    match system {
+        System::Decimal => format(bytes),
+        System::Binary => format_binary(bytes),
     }
+}
 
-    if bytes < PB {
-        return format_to(bytes, Unit::TB);
+fn format_from_table(bytes: u64, table: &[(u64, Unit)]) -> String {
+    for (threshold, unit) in table {
+        if bytes >= *threshold {
+            return format_to(bytes, *unit);
+        }
     }
 
-    format_to(bytes, Unit::PB)
+    format_to(bytes, Unit::B)
 }
 
 /// Format bytes to specific unit byte string
@@ -219,6 +291,21 @@ pub fn format(bytes: u64) -> String {
 /// assert_eq!(bytefmt::format_to(512, bytefmt::Unit::KIB), "0.5 KiB");
 /// ```
 pub fn format_to(bytes: u64, unit: Unit) -> String {
+    format_to_precision(bytes, unit, 2, true)
+}
+
+/// Format bytes to specific unit byte string with a configurable number of
+/// fraction digits, optionally keeping trailing zeros for fixed-width output
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(bytefmt::format_to_precision(1245, bytefmt::Unit::KB, 2, true), "1.25 KB");
+/// assert_eq!(bytefmt::format_to_precision(1245, bytefmt::Unit::KB, 4, true), "1.245 KB");
+/// assert_eq!(bytefmt::format_to_precision(1_000, bytefmt::Unit::KB, 2, false), "1.00 KB");
+/// assert_eq!(bytefmt::format_to_precision(1_000, bytefmt::Unit::KB, 2, true), "1 KB");
+/// ```
+pub fn format_to_precision(bytes: u64, unit: Unit, precision: usize, strip_trailing_zeros: bool) -> String {
     let result = match unit {
         Unit::B => bytes as f64,
         Unit::KB => bytes as f64 / KB as f64,
@@ -226,17 +313,25 @@ pub fn format_to(bytes: u64, unit: Unit) -> String {
         Unit::GB => bytes as f64 / GB as f64,
         Unit::TB => bytes as f64 / TB as f64,
         Unit::PB => bytes as f64 / PB as f64,
+        Unit::EB => bytes as f64 / EB as f64,
         Unit::KIB => bytes as f64 / KIB as f64,
         Unit::MIB => bytes as f64 / MIB as f64,
         Unit::GIB => bytes as f64 / GIB as f64,
         Unit::TIB => bytes as f64 / TIB as f64,
         Unit::PIB => bytes as f64 / PIB as f64,
+        Unit::EIB => bytes as f64 / EIB as f64,
     };
 
-    let mut str = format!("{:.2}", result)
-        .trim_end_matches('0')
-        .trim_end_matches('.')
-        .to_string();
+    let formatted = format!("{:.*}", precision, result);
+
+    let mut str = if strip_trailing_zeros && formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    };
 
     match unit {
         Unit::B => str.push_str(" B"),
@@ -245,16 +340,132 @@ pub fn format_to(bytes: u64, unit: Unit) -> String {
         Unit::GB => str.push_str(" GB"),
         Unit::TB => str.push_str(" TB"),
         Unit::PB => str.push_str(" PB"),
+        Unit::EB => str.push_str(" EB"),
         Unit::KIB => str.push_str(" KiB"),
         Unit::MIB => str.push_str(" MiB"),
         Unit::GIB => str.push_str(" GiB"),
         Unit::TIB => str.push_str(" TiB"),
         Unit::PIB => str.push_str(" PiB"),
+        Unit::EIB => str.push_str(" EiB"),
     }
 
     str
 }
 
+/// A typed wrapper around a byte count, giving `parse`/`format` an ergonomic,
+/// composable type instead of raw `u64`/`f64`.
+///
+/// `Add`, `Sub`, and `Mul<u64>` saturate at `0`/`u64::MAX` instead of
+/// panicking or wrapping on overflow.
+///
+/// # Examples
+///
+/// ```
+/// use bytefmt::{ByteSize, Unit};
+///
+/// let s: ByteSize = "1.23 MB".parse().unwrap();
+/// assert_eq!(s.as_u64(), 1_230_000);
+/// assert_eq!(s.to_string(), "1.23 MB");
+///
+/// let kb = ByteSize::from_unit(1.23, Unit::KB);
+/// assert_eq!(kb.as_u64(), 1_230);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Build a `ByteSize` from a value expressed in the given `Unit`.
+    pub fn from_unit(value: f64, unit: Unit) -> Self {
+        let bytes = match unit {
+            Unit::B => value * B as f64,
+            Unit::KB => value * KB as f64,
+            Unit::MB => value * MB as f64,
+            Unit::GB => value * GB as f64,
+            Unit::TB => value * TB as f64,
+            Unit::PB => value * PB as f64,
+            Unit::EB => value * EB as f64,
+            Unit::KIB => value * KIB as f64,
+            Unit::MIB => value * MIB as f64,
+            Unit::GIB => value * GIB as f64,
+            Unit::TIB => value * TIB as f64,
+            Unit::PIB => value * PIB as f64,
+            Unit::EIB => value * EIB as f64,
+        };
+
+        ByteSize(bytes as u64)
+    }
+
+    /// Returns the wrapped byte count.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::str::FromStr for ByteSize {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(ByteSize)
+    }
+}
+
+impl std::fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", format(self.0))
+    }
+}
+
+impl std::ops::Add for ByteSize {
+    type Output = ByteSize;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ByteSize(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::Sub for ByteSize {
+    type Output = ByteSize;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ByteSize(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl std::ops::Mul<u64> for ByteSize {
+    type Output = ByteSize;
+
+    fn mul(self, rhs: u64) -> Self::Output {
+        ByteSize(self.0.saturating_mul(rhs))
+    }
+}
+
+/// Serializes as the human string produced by `Display` (e.g. `"1.23 MB"`).
+///
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ByteSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes from a human string through `FromStr` (e.g. `"4 GiB"`).
+///
+/// Requires the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ByteSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +502,11 @@ mod tests {
         assert_eq!(parse_size_unit("12.34PB").unwrap(), (12.34_f64, Unit::PB));
         assert_eq!(parse_size_unit("12.34PiB").unwrap(), (12.34_f64, Unit::PIB));
 
+        assert_eq!(parse_size_unit("12.34eb").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34eib").unwrap(), (12.34_f64, Unit::EIB));
+        assert_eq!(parse_size_unit("12.34EB").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34EiB").unwrap(), (12.34_f64, Unit::EIB));
+
         assert_eq!(parse_size_unit("12.34 kb").unwrap(), (12.34_f64, Unit::KB));
         assert_eq!(parse_size_unit("12.34 kib").unwrap(), (12.34_f64, Unit::KIB));
         assert_eq!(parse_size_unit("12.34 KB").unwrap(), (12.34_f64, Unit::KB));
@@ -315,6 +531,19 @@ mod tests {
         assert_eq!(parse_size_unit("12.34 pib").unwrap(), (12.34_f64, Unit::PIB));
         assert_eq!(parse_size_unit("12.34 PB").unwrap(), (12.34_f64, Unit::PB));
         assert_eq!(parse_size_unit("12.34 PiB").unwrap(), (12.34_f64, Unit::PIB));
+
+        assert_eq!(parse_size_unit("12.34 eb").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34 eib").unwrap(), (12.34_f64, Unit::EIB));
+        assert_eq!(parse_size_unit("12.34 EB").unwrap(), (12.34_f64, Unit::EB));
+        assert_eq!(parse_size_unit("12.34 EiB").unwrap(), (12.34_f64, Unit::EIB));
+    }
+
+    #[test]
+    fn test_parse_size_unit_rejects_bare_dots() {
+        assert!(parse_size_unit(".5KB").is_err());
+        assert!(parse_size_unit("5.KB").is_err());
+        assert!(parse_size_unit(".").is_err());
+        assert!(parse_size_unit("1.2.3").is_err());
     }
 
     #[test]
@@ -326,11 +555,18 @@ mod tests {
         assert_eq!(parse("1.23GB").unwrap(), 1_230_000_000);
         assert_eq!(parse("1.23TB").unwrap(), 1_230_000_000_000);
         assert_eq!(parse("1.23PB").unwrap(), 1_230_000_000_000_000);
+        assert_eq!(parse("1.23EB").unwrap(), 1_230_000_000_000_000_000);
         assert_eq!(parse("1.23KIB").unwrap(), 1_259);
         assert_eq!(parse("1.23MIB").unwrap(), 1_289_748);
         assert_eq!(parse("1.23GIB").unwrap(), 1_320_702_443);
         assert_eq!(parse("1.23TIB").unwrap(), 1_352_399_302_164);
         assert_eq!(parse("1.23PIB").unwrap(), 1_384_856_885_416_427);
+        assert_eq!(parse("1.23EIB").unwrap(), 1_418_093_450_666_421_760);
+    }
+
+    #[test]
+    fn test_parse_clamps_on_overflow() {
+        assert_eq!(parse("100EIB").unwrap(), u64::MAX);
     }
 
     #[test]
@@ -341,11 +577,13 @@ mod tests {
         assert_eq!(format!("{:.2}", parse_to("1.23GB", Unit::GB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23TB", Unit::TB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23PB", Unit::PB).unwrap()), "1.23");
+        assert_eq!(format!("{:.2}", parse_to("1.23EB", Unit::EB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23KIB", Unit::KIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23MIB", Unit::MIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23GIB", Unit::GIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23TIB", Unit::TIB).unwrap()), "1.23");
         assert_eq!(format!("{:.2}", parse_to("1.23PIB", Unit::PIB).unwrap()), "1.23");
+        assert_eq!(format!("{:.2}", parse_to("1.23EIB", Unit::EIB).unwrap()), "1.23");
     }
 
     #[test]
@@ -356,6 +594,24 @@ mod tests {
         assert_eq!(format(1_230_000_000), "1.23 GB");
         assert_eq!(format(1_230_000_000_000), "1.23 TB");
         assert_eq!(format(1_230_000_000_000_000), "1.23 PB");
+        assert_eq!(format(1_230_000_000_000_000_000), "1.23 EB");
+    }
+
+    #[test]
+    fn test_format_binary() {
+        assert_eq!(format_binary(123), "123 B");
+        assert_eq!(format_binary(1_259), "1.23 KiB");
+        assert_eq!(format_binary(1_048_576), "1 MiB");
+        assert_eq!(format_binary(1_320_702_443), "1.23 GiB");
+        assert_eq!(format_binary(1_352_399_302_164), "1.23 TiB");
+        assert_eq!(format_binary(1_384_856_885_416_427), "1.23 PiB");
+        assert_eq!(format_binary(1_418_093_450_666_421_760), "1.23 EiB");
+    }
+
+    #[test]
+    fn test_format_with() {
+        assert_eq!(format_with(1_230, System::Decimal), "1.23 KB");
+        assert_eq!(format_with(1_259, System::Binary), "1.23 KiB");
     }
 
 
@@ -372,6 +628,8 @@ mod tests {
         assert_eq!(format_to(1_337_882_312, Unit::GIB), "1.25 GiB");
         assert_eq!(format_to(1_369_991_488_208, Unit::TIB), "1.25 TiB");
         assert_eq!(format_to(1_402_871_283_925_909, Unit::PIB), "1.25 PiB");
+        assert_eq!(format_to(1_250_000_000_000_000_000, Unit::EB), "1.25 EB");
+        assert_eq!(format_to(1_441_151_880_758_558_720, Unit::EIB), "1.25 EiB");
 
         assert_eq!(format_to(500, Unit::KB), "0.5 KB");
         assert_eq!(format_to(500_000, Unit::MB), "0.5 MB");
@@ -383,6 +641,64 @@ mod tests {
         assert_eq!(format_to(536_870_912, Unit::GIB), "0.5 GiB");
         assert_eq!(format_to(549_755_813_888, Unit::TIB), "0.5 TiB");
         assert_eq!(format_to(562_949_953_421_312, Unit::PIB), "0.5 PiB");
+        assert_eq!(format_to(500_000_000_000_000_000, Unit::EB), "0.5 EB");
+        assert_eq!(format_to(576_460_752_303_423_488, Unit::EIB), "0.5 EiB");
+    }
+
+    #[test]
+    fn test_format_to_precision() {
+        assert_eq!(format_to_precision(1_245, Unit::KB, 2, true), "1.25 KB");
+        assert_eq!(format_to_precision(1_245_678, Unit::MB, 4, true), "1.2457 MB");
+        assert_eq!(format_to_precision(1_000, Unit::KB, 2, false), "1.00 KB");
+        assert_eq!(format_to_precision(1_000, Unit::KB, 2, true), "1 KB");
+        assert_eq!(format_to_precision(123, Unit::B, 0, true), "123 B");
+        assert_eq!(format_to_precision(2_000, Unit::B, 0, true), "2000 B");
+        assert_eq!(format_to_precision(10_000_000, Unit::MB, 0, true), "10 MB");
+    }
+
+    #[test]
+    fn test_byte_size_from_str_and_display() {
+        let s: ByteSize = "1.23 MB".parse().unwrap();
+        assert_eq!(s.as_u64(), 1_230_000);
+        assert_eq!(s.to_string(), "1.23 MB");
+
+        assert!("not a size".parse::<ByteSize>().is_err());
+    }
+
+    #[test]
+    fn test_byte_size_from_unit() {
+        assert_eq!(ByteSize::from_unit(1.23, Unit::KB).as_u64(), 1_230);
+        assert_eq!(ByteSize::from_unit(1.23, Unit::MIB).as_u64(), 1_289_748);
+    }
+
+    #[test]
+    fn test_byte_size_arithmetic_and_ord() {
+        let a = ByteSize::from_unit(1.0, Unit::KB);
+        let b = ByteSize::from_unit(500.0, Unit::B);
+
+        assert_eq!((a + b).as_u64(), 1_500);
+        assert_eq!((a - b).as_u64(), 500);
+        assert_eq!((a * 3).as_u64(), 3_000);
+        assert!(a > b);
+    }
+
+    #[test]
+    fn test_byte_size_arithmetic_saturates() {
+        let small = ByteSize::from_unit(1.0, Unit::B);
+        let max = ByteSize(u64::MAX);
+
+        assert_eq!((small - max).as_u64(), 0);
+        assert_eq!((max + small).as_u64(), u64::MAX);
+        assert_eq!((max * 2).as_u64(), u64::MAX);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_byte_size_serde() {
+        let s = ByteSize::from_unit(1.23, Unit::MB);
+
+        assert_eq!(serde_json::to_string(&s).unwrap(), "\"1.23 MB\"");
+        assert_eq!(serde_json::from_str::<ByteSize>("\"1.23 MB\"").unwrap(), s);
     }
 
     #[test]
@@ -399,7 +715,7 @@ mod tests {
 
         // Parse to specific unit
         let kb: f64 = parse_to(input, Unit::KB).unwrap();
-        assert_eq!(kb, 1_230 as f64);
+        assert_eq!(kb, 1_230_f64);
 
         // Format to specific unit
         let kb_str = format_to(bytes, Unit::KB);